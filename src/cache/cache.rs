@@ -1,14 +1,65 @@
-use std::collections::{HashMap, VecDeque};
+use std::borrow::Borrow;
+use std::collections::HashMap;
 use std::hash::Hash;
 use std::fmt;
 
+/// Nœud interne de la liste doublement chaînée indexée qui porte l'ordre de récence.
+/// `prev` pointe vers l'élément plus récemment utilisé, `next` vers l'élément moins
+/// récemment utilisé.
+struct Node<K, V> {
+    key: K,
+    value: V,
+    weight: usize,
+    in_hot: bool,
+    prev: Option<usize>,
+    next: Option<usize>,
+}
+
+/// Politique d'éviction du cache.
+enum Policy {
+    /// LRU classique sur une unique liste de récence.
+    Lru,
+    /// 2Q : une file FIFO "probatoire" pour les clés vues une fois, et une LRU "hot"
+    /// pour celles vues au moins deux fois, afin de résister au balayage ponctuel
+    /// (scan pollution) qui viderait un LRU classique.
+    TwoQ { prob_capacity: usize, hot_capacity: usize },
+}
+
+/// Identifie l'une des deux files utilisées par la politique [`Policy::TwoQ`].
+#[derive(Clone, Copy)]
+enum Queue {
+    Probation,
+    Hot,
+}
+
+/// Fonction de pondération configurée via [`Cache::with_weigher`], qui calcule le poids
+/// d'une entrée à partir de sa clé et de sa valeur.
+type Weigher<K, V> = Box<dyn Fn(&K, &V) -> usize>;
+
 /// Structure représentant un cache avec une capacité limitée.
-/// Ce cache maintient un ensemble de paires clé-valeur, en conservant l'ordre d'insertion
+/// Ce cache maintient un ensemble de paires clé-valeur, en conservant l'ordre de récence
 /// et en éjectant les éléments les moins utilisés lorsque la capacité est atteinte.
+///
+/// En interne, l'ordre de récence est représenté par une liste doublement chaînée
+/// intrusive indexée (`nodes`, avec `head`/`tail` comme extrémités), ce qui permet de
+/// déplacer un élément en tête ou d'éjecter la queue en O(1), plutôt que de parcourir
+/// une `VecDeque` comme auparavant.
 pub struct Cache<K, V> {
     capacity: usize,
-    data: HashMap<K, V>,
-    keys_order: VecDeque<K>,
+    map: HashMap<K, usize>,
+    nodes: Vec<Option<Node<K, V>>>,
+    free: Vec<usize>,
+    head: Option<usize>,
+    tail: Option<usize>,
+    weigher: Option<Weigher<K, V>>,
+    current_weight: usize,
+    policy: Policy,
+    prob_head: Option<usize>,
+    prob_tail: Option<usize>,
+    prob_len: usize,
+    hot_head: Option<usize>,
+    hot_tail: Option<usize>,
+    hot_len: usize,
 }
 
 /// Implémentation de la structure `Cache`.
@@ -38,15 +89,274 @@ where K: Hash + Eq + Clone {
     pub fn new(capacity: usize) -> Cache<K, V> {
         Cache {
             capacity,
-            data: HashMap::new(),
-            keys_order: VecDeque::new(),
+            map: HashMap::new(),
+            nodes: Vec::new(),
+            free: Vec::new(),
+            head: None,
+            tail: None,
+            weigher: None,
+            current_weight: 0,
+            policy: Policy::Lru,
+            prob_head: None,
+            prob_tail: None,
+            prob_len: 0,
+            hot_head: None,
+            hot_tail: None,
+            hot_len: 0,
+        }
+    }
+
+    /// Crée un cache non borné : aucune éjection automatique n'a lieu dans [`Cache::put`].
+    ///
+    /// Utile pour un stockage de type `HashMap` qui garde néanmoins le suivi de la
+    /// récence, par exemple pour un élagage manuel ultérieur via [`Cache::pop_lru`]
+    /// ou [`Cache::set_capacity`].
+    ///
+    /// # Exemple
+    ///
+    /// ```
+    /// use rust_lru::cache::cache::Cache;
+    /// let mut cache = Cache::unbounded();
+    /// for i in 0..1000 {
+    ///     cache.put(i, i * 2);
+    /// }
+    /// assert_eq!(cache.len(), 1000);
+    /// ```
+    pub fn unbounded() -> Cache<K, V> {
+        Cache::new(usize::MAX)
+    }
+
+    /// Crée un nouveau cache dont la capacité borne un poids total plutôt qu'un simple
+    /// nombre d'entrées.
+    ///
+    /// Chaque entrée insérée via [`Cache::put`] se voit attribuer le poids renvoyé par
+    /// `weigher(&key, &value)`; la somme des poids stockés ne dépassera jamais `capacity`.
+    /// Utilisez [`Cache::put_with_weight`] pour fournir un poids explicite sans dépendre
+    /// du `weigher`.
+    ///
+    /// # Exemple
+    ///
+    /// ```
+    /// use rust_lru::cache::cache::Cache;
+    /// let mut cache = Cache::with_weigher(10, |_k: &&str, v: &String| v.len());
+    /// cache.put("key_a", String::from("hello"));
+    /// assert_eq!(cache.weight(), 5);
+    /// ```
+    pub fn with_weigher<F>(capacity: usize, weigher: F) -> Cache<K, V>
+    where F: Fn(&K, &V) -> usize + 'static {
+        let mut cache = Cache::new(capacity);
+        cache.weigher = Some(Box::new(weigher));
+        cache
+    }
+
+    /// Crée un cache utilisant la politique d'éviction 2Q au lieu du LRU classique.
+    ///
+    /// `capacity` est répartie entre une file probatoire FIFO (~25%, pour les clés vues
+    /// une seule fois) et une file LRU "hot" (~75%, pour celles vues au moins deux fois).
+    /// Une clé entre toujours par la file probatoire ; un second accès la promeut en
+    /// `hot`. Les éjections privilégient la file probatoire avant de toucher à la `hot`,
+    /// ce qui protège le cache d'un balayage ponctuel qui viderait un LRU classique.
+    ///
+    /// L'API reste celle de [`CacheOperations`](crate::cache::operation::CacheOperations) :
+    /// `put`/`get`/`remove`/`clear` se comportent de la même façon qu'avec [`Cache::new`]
+    /// du point de vue de l'appelant.
+    ///
+    /// # Exemple
+    ///
+    /// ```
+    /// use rust_lru::cache::cache::Cache;
+    /// let mut cache = Cache::new_2q(4);
+    /// cache.put("key_a", "value_a");
+    /// cache.get(&"key_a"); // 2nd access => promue en hot
+    /// assert_eq!(cache.get(&"key_a"), Some(&"value_a"));
+    /// ```
+    pub fn new_2q(capacity: usize) -> Cache<K, V> {
+        let prob_capacity = capacity / 4;
+        let hot_capacity = capacity - prob_capacity;
+        let mut cache = Cache::new(capacity);
+        cache.policy = Policy::TwoQ { prob_capacity, hot_capacity };
+        cache
+    }
+
+    /// Calcule le poids d'une paire clé-valeur : celui renvoyé par le `weigher` configuré,
+    /// ou `1` si le cache n'en a pas (comportement "par entrée" historique).
+    fn weight_for(&self, key: &K, value: &V) -> usize {
+        match &self.weigher {
+            Some(weigher) => weigher(key, value),
+            None => 1,
+        }
+    }
+
+    /// Détache le nœud `idx` de la liste chaînée, sans le libérer.
+    fn detach(&mut self, idx: usize) {
+        let (prev, next) = {
+            let node = self.nodes[idx].as_ref().expect("detach: noeud absent");
+            (node.prev, node.next)
+        };
+
+        match prev {
+            Some(p) => self.nodes[p].as_mut().unwrap().next = next,
+            None => self.head = next,
+        }
+        match next {
+            Some(n) => self.nodes[n].as_mut().unwrap().prev = prev,
+            None => self.tail = prev,
+        }
+    }
+
+    /// Raccroche le nœud `idx` en tête de liste (position la plus récemment utilisée).
+    fn attach_front(&mut self, idx: usize) {
+        {
+            let node = self.nodes[idx].as_mut().expect("attach_front: noeud absent");
+            node.prev = None;
+            node.next = self.head;
+        }
+
+        if let Some(h) = self.head {
+            self.nodes[h].as_mut().unwrap().prev = Some(idx);
+        }
+        self.head = Some(idx);
+        if self.tail.is_none() {
+            self.tail = Some(idx);
+        }
+    }
+
+    /// Déplace le nœud `idx` en tête de liste (marque l'élément comme récemment utilisé).
+    fn move_to_front(&mut self, idx: usize) {
+        if self.head == Some(idx) {
+            return;
+        }
+        self.detach(idx);
+        self.attach_front(idx);
+    }
+
+    /// Éjecte l'élément le moins récemment utilisé (la queue de la liste), s'il existe.
+    fn evict_lru(&mut self) {
+        if let Some(idx) = self.tail {
+            self.detach(idx);
+            let node = self.nodes[idx].take().expect("evict_lru: noeud absent");
+            self.map.remove(&node.key);
+            self.current_weight -= node.weight;
+            self.free.push(idx);
+        }
+    }
+
+    /// Alloue un nouveau nœud et retourne son index, en réutilisant un emplacement libre si possible.
+    fn alloc_node(&mut self, key: K, value: V, weight: usize) -> usize {
+        let node = Node { key, value, weight, in_hot: false, prev: None, next: None };
+        if let Some(idx) = self.free.pop() {
+            self.nodes[idx] = Some(node);
+            idx
+        } else {
+            self.nodes.push(Some(node));
+            self.nodes.len() - 1
+        }
+    }
+
+    /// Renvoie les extrémités (tête, queue) de la file 2Q demandée.
+    fn tq_ends(&self, queue: Queue) -> (Option<usize>, Option<usize>) {
+        match queue {
+            Queue::Probation => (self.prob_head, self.prob_tail),
+            Queue::Hot => (self.hot_head, self.hot_tail),
+        }
+    }
+
+    /// Écrit les nouvelles extrémités d'une file 2Q.
+    fn tq_set_ends(&mut self, queue: Queue, head: Option<usize>, tail: Option<usize>) {
+        match queue {
+            Queue::Probation => { self.prob_head = head; self.prob_tail = tail; }
+            Queue::Hot => { self.hot_head = head; self.hot_tail = tail; }
+        }
+    }
+
+    /// Détache le nœud `idx` de la file 2Q `queue`, sans le libérer.
+    fn tq_detach(&mut self, idx: usize, queue: Queue) {
+        let (mut head, mut tail) = self.tq_ends(queue);
+        let (prev, next) = {
+            let node = self.nodes[idx].as_ref().expect("tq_detach: noeud absent");
+            (node.prev, node.next)
+        };
+
+        match prev {
+            Some(p) => self.nodes[p].as_mut().unwrap().next = next,
+            None => head = next,
+        }
+        match next {
+            Some(n) => self.nodes[n].as_mut().unwrap().prev = prev,
+            None => tail = prev,
+        }
+        self.tq_set_ends(queue, head, tail);
+
+        match queue {
+            Queue::Probation => self.prob_len -= 1,
+            Queue::Hot => self.hot_len -= 1,
+        }
+    }
+
+    /// Raccroche le nœud `idx` en tête de la file 2Q `queue`.
+    fn tq_attach_front(&mut self, idx: usize, queue: Queue) {
+        let (head, tail) = self.tq_ends(queue);
+        {
+            let node = self.nodes[idx].as_mut().expect("tq_attach_front: noeud absent");
+            node.prev = None;
+            node.next = head;
+        }
+
+        if let Some(h) = head {
+            self.nodes[h].as_mut().unwrap().prev = Some(idx);
+        }
+        let new_tail = if tail.is_none() { Some(idx) } else { tail };
+        self.tq_set_ends(queue, Some(idx), new_tail);
+
+        match queue {
+            Queue::Probation => self.prob_len += 1,
+            Queue::Hot => self.hot_len += 1,
+        }
+    }
+
+    /// Éjecte et renvoie l'élément le moins récent de la file 2Q `queue`, s'il existe.
+    fn tq_evict_tail(&mut self, queue: Queue) -> Option<(K, V)> {
+        let (_, tail) = self.tq_ends(queue);
+        let idx = tail?;
+        self.tq_detach(idx, queue);
+        let node = self.nodes[idx].take().expect("tq_evict_tail: noeud absent");
+        self.map.remove(&node.key);
+        self.current_weight -= node.weight;
+        self.free.push(idx);
+        Some((node.key, node.value))
+    }
+
+    /// Marque le nœud `idx` comme récemment utilisé, selon la politique d'éviction active :
+    /// déplacement en tête de la liste LRU classique, ou promotion probatoire → hot / simple
+    /// rafraîchissement au sein de la file hot pour la politique 2Q.
+    fn touch(&mut self, idx: usize) {
+        match self.policy {
+            Policy::Lru => self.move_to_front(idx),
+            Policy::TwoQ { hot_capacity, .. } => {
+                let in_hot = self.nodes[idx].as_ref().unwrap().in_hot;
+                if in_hot {
+                    self.tq_detach(idx, Queue::Hot);
+                    self.tq_attach_front(idx, Queue::Hot);
+                } else {
+                    self.tq_detach(idx, Queue::Probation);
+                    self.nodes[idx].as_mut().unwrap().in_hot = true;
+                    self.tq_attach_front(idx, Queue::Hot);
+                    // Ne jamais éjecter le nœud qu'on vient de promouvoir : avec
+                    // `hot_capacity == 0` (`new_2q(0)`), `idx` serait
+                    // le seul nœud de la file hot et se retrouverait évincé juste après sa
+                    // promotion, laissant l'appelant avec un nœud déjà libéré.
+                    if self.hot_len > hot_capacity && self.hot_tail != Some(idx) {
+                        self.tq_evict_tail(Queue::Hot);
+                    }
+                }
+            }
         }
     }
 
     /// Ajoute une paire clé-valeur dans le cache.
     ///
     /// Cette fonction met à jour ou ajoute un élément dans le cache. Si la clé existe déjà,
-    /// la valeur est mise à jour et sa position dans l'ordre des clés est modifiée.
+    /// la valeur est mise à jour et sa position dans l'ordre de récence est modifiée.
     /// Si la capacité maximale du cache est atteinte, l'élément le moins utilisé est supprimé.
     ///
     /// # Paramètres
@@ -63,20 +373,188 @@ where K: Hash + Eq + Clone {
     /// assert_eq!(cache.get(&"key_a"), Some(&"value_a"));
     /// ```
     pub fn put(&mut self, key: K, value: V) {
-        if self.data.contains_key(&key) {
-            self.keys_order.retain(|k| k != &key);
-        } else if self.data.len() == self.capacity {
-            if let Some(oldest) = self.keys_order.pop_front() {
-                self.data.remove(&oldest);
+        if let Policy::TwoQ { prob_capacity, .. } = self.policy {
+            self.put_2q(key, value, prob_capacity);
+            return;
+        }
+
+        if self.weigher.is_some() {
+            let weight = self.weight_for(&key, &value);
+            self.put_with_weight(key, value, weight);
+            return;
+        }
+
+        if let Some(&idx) = self.map.get(&key) {
+            self.nodes[idx].as_mut().unwrap().value = value;
+            self.move_to_front(idx);
+            return;
+        } else if self.map.len() == self.capacity {
+            self.evict_lru();
+        }
+
+        let idx = self.alloc_node(key.clone(), value, 1);
+        self.attach_front(idx);
+        self.map.insert(key, idx);
+        self.current_weight += 1;
+    }
+
+    /// Ajoute ou met à jour une entrée sous la politique [`Policy::TwoQ`] : une clé
+    /// nouvelle entre dans la file probatoire (éjectant l'entrée probatoire la plus
+    /// ancienne si celle-ci est pleine) ; une clé déjà connue est mise à jour et
+    /// replacée en tête de la file qu'elle occupe déjà.
+    fn put_2q(&mut self, key: K, value: V, prob_capacity: usize) {
+        if let Some(&idx) = self.map.get(&key) {
+            let queue = if self.nodes[idx].as_ref().unwrap().in_hot { Queue::Hot } else { Queue::Probation };
+            self.tq_detach(idx, queue);
+            self.nodes[idx].as_mut().unwrap().value = value;
+            self.tq_attach_front(idx, queue);
+            return;
+        }
+
+        if self.prob_len >= prob_capacity {
+            self.tq_evict_tail(Queue::Probation);
+        }
+
+        let idx = self.alloc_node(key.clone(), value, 1);
+        self.tq_attach_front(idx, Queue::Probation);
+        self.map.insert(key, idx);
+        self.current_weight += 1;
+    }
+
+    /// Ajoute une paire clé-valeur en lui attribuant explicitement `weight`, sans passer
+    /// par le `weigher` configuré via [`Cache::with_weigher`].
+    ///
+    /// Si `weight` dépasse `capacity` à lui seul, l'insertion est refusée (l'élément ne
+    /// tiendrait jamais dans le cache) et la fonction renvoie `false` sans rien modifier.
+    /// Sinon, les entrées les moins récemment utilisées sont éjectées, une par une, jusqu'à
+    /// ce que `weight() + weight <= capacity`, quitte à vider le cache dans le pire des cas.
+    /// Sous la politique 2Q (voir [`Cache::new_2q`]), `weight` est ignoré : l'entrée est
+    /// insérée comme par [`Cache::put`] et la fonction renvoie toujours `true`.
+    ///
+    /// # Exemple
+    ///
+    /// ```
+    /// use rust_lru::cache::cache::Cache;
+    /// let mut cache = Cache::new(10);
+    /// assert!(cache.put_with_weight("key_a", "value_a", 4));
+    /// assert_eq!(cache.weight(), 4);
+    /// assert!(!cache.put_with_weight("key_b", "value_b", 20));
+    /// ```
+    pub fn put_with_weight(&mut self, key: K, value: V, weight: usize) -> bool {
+        if let Policy::TwoQ { prob_capacity, .. } = self.policy {
+            // La politique 2Q ne pondère pas ses files (une entrée y vaut toujours 1, voir
+            // `put_2q`) : comme `put` le fait déjà pour le `weigher` configuré via
+            // `with_weigher`, on ignore `weight` et on route vers le chemin 2Q plutôt que
+            // de manipuler la liste LRU classique, ce qui désynchroniserait les files
+            // probatoire/hot du nœud (et ferait paniquer un `touch` ultérieur).
+            self.put_2q(key, value, prob_capacity);
+            return true;
+        }
+
+        if weight > self.capacity {
+            return false;
+        }
+
+        if let Some(&idx) = self.map.get(&key) {
+            self.detach(idx);
+            let old_weight = self.nodes[idx].as_ref().unwrap().weight;
+            self.current_weight -= old_weight;
+
+            while self.current_weight + weight > self.capacity {
+                self.evict_lru();
+            }
+
+            let node = self.nodes[idx].as_mut().unwrap();
+            node.value = value;
+            node.weight = weight;
+            self.current_weight += weight;
+            self.attach_front(idx);
+            return true;
+        }
+
+        while self.current_weight + weight > self.capacity {
+            self.evict_lru();
+        }
+
+        let idx = self.alloc_node(key.clone(), value, weight);
+        self.attach_front(idx);
+        self.map.insert(key, idx);
+        self.current_weight += weight;
+        true
+    }
+
+    /// Lit ou insère en une seule recherche : si `key` est déjà présente, `on_modify` est
+    /// appelé sur sa valeur existante ; sinon, `on_insert` calcule la valeur à insérer
+    /// (en respectant l'éviction habituelle). Dans les deux cas, renvoie une référence
+    /// mutable vers la valeur, et l'entrée est marquée comme récemment utilisée.
+    ///
+    /// Évite le coût d'un `get` suivi d'un `put` séparé, qui hacherait et réordonnerait
+    /// la clé deux fois pour le cas le plus courant d'un cache "lire-ou-calculer".
+    ///
+    /// # Exemple
+    ///
+    /// ```
+    /// use rust_lru::cache::cache::Cache;
+    /// let mut cache: Cache<&str, i32> = Cache::new(2);
+    /// cache.put_or_modify("key_a", |_| 1, |_, v| *v += 1);
+    /// assert_eq!(cache.peek(&"key_a"), Some(&1));
+    /// cache.put_or_modify("key_a", |_| 100, |_, v| *v += 1);
+    /// assert_eq!(cache.peek(&"key_a"), Some(&2));
+    /// ```
+    pub fn put_or_modify<F, G>(&mut self, key: K, on_insert: F, on_modify: G) -> &mut V
+    where F: FnOnce(&K) -> V, G: FnOnce(&K, &mut V) {
+        if let Some(&idx) = self.map.get(&key) {
+            // `touch` ne doit jamais libérer `idx` ici, y compris sous 2Q avec un
+            // `hot_capacity` dégénéré (voir `touch`) : sinon ce `.expect()` paniquerait
+            // sur une clé pourtant valide.
+            self.touch(idx);
+            let node = self.nodes[idx].as_mut().expect("put_or_modify: touch a libéré le nœud qu'il venait de promouvoir");
+            on_modify(&key, &mut node.value);
+            return &mut node.value;
+        }
+
+        let value = on_insert(&key);
+
+        if let Policy::TwoQ { prob_capacity, .. } = self.policy {
+            if self.prob_len >= prob_capacity {
+                self.tq_evict_tail(Queue::Probation);
             }
+
+            let idx = self.alloc_node(key.clone(), value, 1);
+            self.tq_attach_front(idx, Queue::Probation);
+            self.map.insert(key, idx);
+            self.current_weight += 1;
+            return &mut self.nodes[idx].as_mut().unwrap().value;
+        }
+
+        let mut weight = self.weight_for(&key, &value);
+
+        if self.weigher.is_some() {
+            // Contrairement à `put_with_weight`, cette fonction doit toujours renvoyer une
+            // référence vers une valeur insérée : elle ne peut pas simplement refuser
+            // l'entrée. Si le poids dépasse `capacity` à lui seul, on le plafonne pour que
+            // la boucle d'éviction ci-dessous termine au lieu de tourner indéfiniment une
+            // fois le cache vidé.
+            weight = weight.min(self.capacity);
+            while self.current_weight + weight > self.capacity {
+                self.evict_lru();
+            }
+        } else if self.map.len() == self.capacity {
+            self.evict_lru();
         }
 
-        self.data.insert(key.clone(), value);
-        self.keys_order.push_back(key);
+        let idx = self.alloc_node(key.clone(), value, weight);
+        self.attach_front(idx);
+        self.map.insert(key, idx);
+        self.current_weight += weight;
+        &mut self.nodes[idx].as_mut().unwrap().value
     }
 
     /// Récupère la valeur associée à la clé dans le cache.
     ///
+    /// Accepte toute forme empruntée `Q` de `K` (comme `HashMap::get`), ce qui évite
+    /// par exemple d'allouer une `String` pour chercher avec un `&str`.
+    ///
     /// # Paramètres
     ///
     /// - `key`: La clé de l'élément à récupérer.
@@ -93,17 +571,55 @@ where K: Hash + Eq + Clone {
     /// cache.put("key_a", "value_a");
     /// assert_eq!(cache.get(&"key_a"), Some(&"value_a"));
     /// ```
-    pub fn get(&mut self, key: &K) -> Option<&V> {
-        if self.data.contains_key(key) {
-            self.keys_order.retain(|k| k != key);
-            self.keys_order.push_front(key.clone());
-            return self.data.get(key);
-        }
-        None
+    pub fn get<Q>(&mut self, key: &Q) -> Option<&V>
+    where K: Borrow<Q>, Q: Hash + Eq + ?Sized {
+        let idx = *self.map.get(key)?;
+        self.touch(idx);
+        self.nodes[idx].as_ref().map(|node| &node.value)
+    }
+
+    /// Récupère une référence mutable à la valeur associée à la clé, en la marquant
+    /// comme récemment utilisée (comme [`Cache::get`]).
+    ///
+    /// # Exemple
+    ///
+    /// ```
+    /// use rust_lru::cache::cache::Cache;
+    /// let mut cache = Cache::new(1);
+    /// cache.put("key_a", "value_a");
+    /// if let Some(value) = cache.get_mut(&"key_a") {
+    ///     *value = "value_updated";
+    /// }
+    /// assert_eq!(cache.get(&"key_a"), Some(&"value_updated"));
+    /// ```
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where K: Borrow<Q>, Q: Hash + Eq + ?Sized {
+        let idx = *self.map.get(key)?;
+        self.touch(idx);
+        self.nodes[idx].as_mut().map(|node| &mut node.value)
+    }
+
+    /// Lit la valeur associée à la clé sans modifier l'ordre de récence, contrairement à
+    /// [`Cache::get`]. Utile pour l'inspection ou le débogage.
+    ///
+    /// # Exemple
+    ///
+    /// ```
+    /// use rust_lru::cache::cache::Cache;
+    /// let mut cache = Cache::new(1);
+    /// cache.put("key_a", "value_a");
+    /// assert_eq!(cache.peek(&"key_a"), Some(&"value_a"));
+    /// ```
+    pub fn peek<Q>(&self, key: &Q) -> Option<&V>
+    where K: Borrow<Q>, Q: Hash + Eq + ?Sized {
+        let idx = *self.map.get(key)?;
+        self.nodes[idx].as_ref().map(|node| &node.value)
     }
 
     /// Supprime une paire clé-valeur du cache.
     ///
+    /// Accepte toute forme empruntée `Q` de `K`, au même titre que [`Cache::get`].
+    ///
     /// # Paramètres
     ///
     /// - `key`: La clé de l'élément à supprimer.
@@ -122,13 +638,53 @@ where K: Hash + Eq + Clone {
     /// cache.remove(&"key_a");
     /// assert_eq!(cache.get(&"key_a"), None);
     /// ```
-    pub fn remove(&mut self, key: &K) -> Option<V> {
-        if self.data.contains_key(key) {
-            self.keys_order.retain(|k| k != key);
-            self.data.remove(key)
-        } else {
-            None
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where K: Borrow<Q>, Q: Hash + Eq + ?Sized {
+        let idx = self.map.remove(key)?;
+        match self.policy {
+            Policy::Lru => self.detach(idx),
+            Policy::TwoQ { .. } => {
+                let queue = if self.nodes[idx].as_ref().unwrap().in_hot { Queue::Hot } else { Queue::Probation };
+                self.tq_detach(idx, queue);
+            }
+        }
+        let node = self.nodes[idx].take().expect("remove: noeud absent");
+        self.current_weight -= node.weight;
+        self.free.push(idx);
+        Some(node.value)
+    }
+
+    /// Supprime et renvoie la paire clé-valeur la moins récemment utilisée.
+    ///
+    /// Permet de vider le cache en commençant par les entrées les plus anciennes, ou
+    /// d'implémenter une logique de débordement (par ex. vers un disque) maison.
+    /// Sous la politique [`Policy::TwoQ`], la file probatoire est vidée avant de
+    /// toucher à la file hot, comme pour les éjections automatiques.
+    ///
+    /// # Exemple
+    ///
+    /// ```
+    /// use rust_lru::cache::cache::Cache;
+    /// let mut cache = Cache::new(2);
+    /// cache.put("key_a", "value_a");
+    /// cache.put("key_b", "value_b");
+    /// assert_eq!(cache.pop_lru(), Some(("key_a", "value_a")));
+    /// ```
+    pub fn pop_lru(&mut self) -> Option<(K, V)> {
+        if let Policy::TwoQ { .. } = self.policy {
+            if self.prob_tail.is_some() {
+                return self.tq_evict_tail(Queue::Probation);
+            }
+            return self.tq_evict_tail(Queue::Hot);
         }
+
+        let idx = self.tail?;
+        self.detach(idx);
+        let node = self.nodes[idx].take().expect("pop_lru: noeud absent");
+        self.map.remove(&node.key);
+        self.current_weight -= node.weight;
+        self.free.push(idx);
+        Some((node.key, node.value))
     }
 
     /// Vide le cache.
@@ -143,8 +699,18 @@ where K: Hash + Eq + Clone {
     /// assert_eq!(cache.is_empty(), true);
     /// ```
     pub fn clear(&mut self) {
-        self.data.clear();
-        self.keys_order.clear();
+        self.map.clear();
+        self.nodes.clear();
+        self.free.clear();
+        self.head = None;
+        self.tail = None;
+        self.current_weight = 0;
+        self.prob_head = None;
+        self.prob_tail = None;
+        self.prob_len = 0;
+        self.hot_head = None;
+        self.hot_tail = None;
+        self.hot_len = 0;
     }
 
     /// Retourne le nombre d'éléments dans le cache.
@@ -162,7 +728,7 @@ where K: Hash + Eq + Clone {
     /// assert_eq!(cache.len(), 1);
     /// ```
     pub fn len(&self) -> usize {
-        self.data.len()
+        self.map.len()
     }
 
     /// Vérifie si le cache est vide.
@@ -181,7 +747,221 @@ where K: Hash + Eq + Clone {
     /// assert_eq!(cache.is_empty(), false);
     /// ```
     pub fn is_empty(&self) -> bool {
-        self.data.is_empty()
+        self.map.is_empty()
+    }
+
+    /// Retourne le poids total actuellement stocké dans le cache.
+    ///
+    /// En l'absence de `weigher` (voir [`Cache::with_weigher`]) et de [`Cache::put_with_weight`],
+    /// chaque entrée pèse `1` et cette valeur coïncide avec [`Cache::len`].
+    ///
+    /// # Exemple
+    ///
+    /// ```
+    /// use rust_lru::cache::cache::Cache;
+    /// let mut cache = Cache::new(3);
+    /// cache.put("key_a", "value_a");
+    /// assert_eq!(cache.weight(), 1);
+    /// ```
+    pub fn weight(&self) -> usize {
+        self.current_weight
+    }
+
+    /// Retourne la capacité actuelle du cache.
+    ///
+    /// # Exemple
+    ///
+    /// ```
+    /// use rust_lru::cache::cache::Cache;
+    /// let cache: Cache<&str, &str> = Cache::new(3);
+    /// assert_eq!(cache.capacity(), 3);
+    /// ```
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Redéfinit la capacité du cache.
+    ///
+    /// Si `cap` réduit la capacité en-dessous du nombre d'éléments actuellement stockés,
+    /// ou (pour un cache à poids, voir [`Cache::with_weigher`] / [`Cache::put_with_weight`])
+    /// en-dessous du poids total actuellement stocké, les entrées les moins récemment
+    /// utilisées sont immédiatement éjectées jusqu'à ce que `len() <= cap` et
+    /// `weight() <= cap`. Agrandir la capacité ne fait qu'en relever la limite.
+    /// Sous la politique 2Q, `cap` est de nouveau répartie entre les deux files comme
+    /// dans [`Cache::new_2q`], et chaque file est ramenée à sa nouvelle taille.
+    ///
+    /// # Exemple
+    ///
+    /// ```
+    /// use rust_lru::cache::cache::Cache;
+    /// let mut cache = Cache::new(3);
+    /// cache.put("key_a", "value_a");
+    /// cache.put("key_b", "value_b");
+    /// cache.put("key_c", "value_c");
+    /// cache.set_capacity(1);
+    /// assert_eq!(cache.len(), 1);
+    /// assert_eq!(cache.get(&"key_c"), Some(&"value_c"));
+    /// ```
+    pub fn set_capacity(&mut self, cap: usize) {
+        self.capacity = cap;
+        if let Policy::TwoQ { .. } = self.policy {
+            let prob_capacity = cap / 4;
+            let hot_capacity = cap - prob_capacity;
+            self.policy = Policy::TwoQ { prob_capacity, hot_capacity };
+            while self.prob_len > prob_capacity {
+                self.tq_evict_tail(Queue::Probation);
+            }
+            while self.hot_len > hot_capacity {
+                self.tq_evict_tail(Queue::Hot);
+            }
+        }
+        while self.map.len() > self.capacity || self.current_weight > self.capacity {
+            if self.pop_lru().is_none() {
+                break;
+            }
+        }
+    }
+
+    /// Renvoie les extrémités de départ d'un parcours de l'ordre de récence : la tête de
+    /// la liste LRU classique, ou d'abord la file `hot` puis la file probatoire sous 2Q.
+    fn iter_starts(&self) -> (Option<usize>, Option<usize>) {
+        match self.policy {
+            Policy::Lru => (self.head, None),
+            Policy::TwoQ { .. } => (self.hot_head, self.prob_head),
+        }
+    }
+
+    /// Itère sur les paires clé-valeur, de la plus récemment utilisée à la moins
+    /// récemment utilisée, sans modifier l'ordre de récence (contrairement à [`Cache::get`]).
+    ///
+    /// # Exemple
+    ///
+    /// ```
+    /// use rust_lru::cache::cache::Cache;
+    /// let mut cache = Cache::new(2);
+    /// cache.put("key_a", "value_a");
+    /// cache.put("key_b", "value_b");
+    /// let entries: Vec<_> = cache.iter().collect();
+    /// assert_eq!(entries, vec![(&"key_b", &"value_b"), (&"key_a", &"value_a")]);
+    /// ```
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        let (cursor, next_chain) = self.iter_starts();
+        Iter { nodes: &self.nodes, cursor, next_chain }
+    }
+
+    /// Itère sur les paires clé-valeur mutable, dans le même ordre que [`Cache::iter`],
+    /// sans modifier l'ordre de récence.
+    ///
+    /// # Exemple
+    ///
+    /// ```
+    /// use rust_lru::cache::cache::Cache;
+    /// let mut cache = Cache::new(2);
+    /// cache.put("key_a", 1);
+    /// for (_, value) in cache.iter_mut() {
+    ///     *value += 10;
+    /// }
+    /// assert_eq!(cache.peek(&"key_a"), Some(&11));
+    /// ```
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        let (cursor, next_chain) = self.iter_starts();
+        IterMut {
+            nodes: self.nodes.as_mut_slice() as *mut [Option<Node<K, V>>],
+            cursor,
+            next_chain,
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    /// Itère sur les clés, de la plus récemment utilisée à la moins récemment utilisée.
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        Keys(self.iter())
+    }
+
+    /// Itère sur les valeurs, de la plus récemment utilisée à la moins récemment utilisée.
+    pub fn values(&self) -> Values<'_, K, V> {
+        Values(self.iter())
+    }
+}
+
+/// Itérateur produit par [`Cache::iter`] : parcourt l'ordre de récence en lecture seule.
+pub struct Iter<'a, K, V> {
+    nodes: &'a [Option<Node<K, V>>],
+    cursor: Option<usize>,
+    next_chain: Option<usize>,
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(idx) = self.cursor {
+                let node = self.nodes[idx].as_ref().expect("Iter: noeud absent");
+                self.cursor = node.next;
+                return Some((&node.key, &node.value));
+            }
+            if self.next_chain.is_some() {
+                self.cursor = self.next_chain.take();
+                continue;
+            }
+            return None;
+        }
+    }
+}
+
+/// Itérateur produit par [`Cache::iter_mut`] : parcourt l'ordre de récence en donnant un
+/// accès mutable à chaque valeur, sans jamais modifier cet ordre.
+pub struct IterMut<'a, K, V> {
+    nodes: *mut [Option<Node<K, V>>],
+    cursor: Option<usize>,
+    next_chain: Option<usize>,
+    _marker: std::marker::PhantomData<&'a mut [Option<Node<K, V>>]>,
+}
+
+impl<'a, K, V> Iterator for IterMut<'a, K, V> {
+    type Item = (&'a K, &'a mut V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(idx) = self.cursor {
+                // SAFETY: le parcours visite chaque index au plus une fois, donc l'emprunt
+                // mutable `'a` rendu ici n'entre jamais en alias avec un autre emprunt déjà
+                // produit par cet itérateur.
+                let node = unsafe {
+                    (*self.nodes)[idx].as_mut().expect("IterMut: noeud absent")
+                };
+                self.cursor = node.next;
+                return Some((&node.key, &mut node.value));
+            }
+            if self.next_chain.is_some() {
+                self.cursor = self.next_chain.take();
+                continue;
+            }
+            return None;
+        }
+    }
+}
+
+/// Itérateur produit par [`Cache::keys`].
+pub struct Keys<'a, K, V>(Iter<'a, K, V>);
+
+impl<'a, K, V> Iterator for Keys<'a, K, V> {
+    type Item = &'a K;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(k, _)| k)
+    }
+}
+
+/// Itérateur produit par [`Cache::values`].
+pub struct Values<'a, K, V>(Iter<'a, K, V>);
+
+impl<'a, K, V> Iterator for Values<'a, K, V> {
+    type Item = &'a V;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(_, v)| v)
     }
 }
 
@@ -189,8 +969,17 @@ where K: Hash + Eq + Clone {
 impl<K: fmt::Display + Hash + Eq + Clone, V> fmt::Display for Cache<K, V> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "Cache [Capacity: {}, Keys: [", self.capacity)?;
-        for key in &self.keys_order {
-            write!(f, "{}, ", key)?;
+        let starts: &[Option<usize>] = match self.policy {
+            Policy::Lru => &[self.head],
+            Policy::TwoQ { .. } => &[self.hot_head, self.prob_head],
+        };
+        for &start in starts {
+            let mut cursor = start;
+            while let Some(idx) = cursor {
+                let node = self.nodes[idx].as_ref().expect("Display: noeud absent");
+                write!(f, "{}, ", node.key)?;
+                cursor = node.next;
+            }
         }
         write!(f, "]]")
     }
@@ -36,12 +36,12 @@ mod tests {
         //Display items
         //println!("Cache test_lru_cache_eviction_order [B - A] : {}", cache);
 
-        // Replace key_b => key_c
+        // Replace key_a (now LRU, since key_b was just accessed) => key_c
         cache.put("key_c".to_string(), "value_c".to_string());
 
         // Verification
-        assert_eq!(cache.get(&"key_a".to_string()), Some(&"value_a".to_string()));
-        assert_eq!(cache.get(&"key_b".to_string()), None); // "key_b" ?
+        assert_eq!(cache.get(&"key_a".to_string()), None);
+        assert_eq!(cache.get(&"key_b".to_string()), Some(&"value_b".to_string()));
         assert_eq!(cache.get(&"key_c".to_string()), Some(&"value_c".to_string()));
 
         //Display cache
@@ -98,12 +98,12 @@ mod tests {
         // Get "key_a" => more recent
         cache.get(&"key_a".to_string());
 
-        // Add new item
+        // Add new item => evicts key_b, the LRU entry now that key_a was re-accessed
         cache.put("key_e".to_string(), "value_e".to_string());
 
         // Verification
-        assert_eq!(cache.get(&"key_a".to_string()), None);
-        assert_eq!(cache.get(&"key_b".to_string()), Some(&"value_b".to_string()));
+        assert_eq!(cache.get(&"key_a".to_string()), Some(&"value_a".to_string()));
+        assert_eq!(cache.get(&"key_b".to_string()), None);
         assert_eq!(cache.get(&"key_c".to_string()), Some(&"value_c".to_string()));
         assert_eq!(cache.get(&"key_d".to_string()), Some(&"value_d".to_string()));
         assert_eq!(cache.get(&"key_e".to_string()), Some(&"value_e".to_string()));
@@ -111,4 +111,250 @@ mod tests {
         //Display cache
         //println!("Cache test_lru_cache_eviction_with_full_capacity : {}", cache);
     }
+
+    // Test => weighted capacity via with_weigher, eviction keeps total weight <= capacity
+    #[test]
+    fn test_with_weigher_evicts_by_total_weight() {
+        // Capacity 10, weight = string length
+        let mut cache = Cache::with_weigher(10, |_k: &&str, v: &String| v.len());
+
+        cache.put("key_a", "12345".to_string()); // weight 5
+        cache.put("key_b", "123".to_string()); // weight 3, total 8
+
+        assert_eq!(cache.weight(), 8);
+
+        // Pushes total weight past capacity => evicts LRU ("key_a") to make room
+        cache.put("key_c", "1234".to_string()); // weight 4
+
+        assert_eq!(cache.get(&"key_a"), None);
+        assert_eq!(cache.get(&"key_b"), Some(&"123".to_string()));
+        assert_eq!(cache.get(&"key_c"), Some(&"1234".to_string()));
+        assert_eq!(cache.weight(), 7);
+    }
+
+    // Test => put_with_weight rejects an entry heavier than the whole cache
+    #[test]
+    fn test_put_with_weight_rejects_oversized_entry() {
+        let mut cache: Cache<&str, &str> = Cache::new(10);
+
+        assert!(cache.put_with_weight("key_a", "value_a", 4));
+        assert_eq!(cache.weight(), 4);
+        assert!(!cache.put_with_weight("key_b", "value_b", 20));
+        assert_eq!(cache.get(&"key_b"), None);
+    }
+
+    // Test => put_with_weight on a new_2q cache must not desync the 2Q bookkeeping
+    // (regression: a subsequent get() used to panic with an underflow in tq_detach)
+    #[test]
+    fn test_put_with_weight_under_2q_does_not_panic() {
+        let mut cache = Cache::new_2q(8);
+
+        cache.put_with_weight(1, "one", 1);
+        assert_eq!(cache.get(&1), Some(&"one")); // must not panic
+        assert_eq!(cache.get(&1), Some(&"one")); // promoted to hot, must still not panic
+    }
+
+    // Test => 2Q resists scan pollution: a one-off sweep of never-reused keys stays in
+    // the probationary queue and can't evict the hot working set.
+    #[test]
+    fn test_new_2q_resists_scan_pollution() {
+        // Capacity 8 => prob_capacity 2, hot_capacity 6
+        let mut cache: Cache<String, String> = Cache::new_2q(8);
+
+        // Build a hot working set: 3 keys, each accessed twice to get promoted
+        for key in ["key_a", "key_b", "key_c"] {
+            cache.put(key.to_string(), key.to_string());
+            cache.get(&key.to_string()); // 2nd access => promoted to hot
+        }
+
+        // A scan of keys seen only once: far more than the probationary capacity
+        for i in 0..20 {
+            cache.put(format!("scan_{}", i), format!("scan_{}", i));
+        }
+
+        // The hot working set survives the scan untouched
+        assert_eq!(cache.get(&"key_a".to_string()), Some(&"key_a".to_string()));
+        assert_eq!(cache.get(&"key_b".to_string()), Some(&"key_b".to_string()));
+        assert_eq!(cache.get(&"key_c".to_string()), Some(&"key_c".to_string()));
+    }
+
+    // Test => new_2q with a degenerate hot_capacity of 0 must not panic or destroy the
+    // entry being accessed on its promoting access (regression, see touch())
+    #[test]
+    fn test_new_2q_zero_capacity_promotion_does_not_panic() {
+        let mut cache = Cache::new_2q(0);
+
+        cache.put(1, "one");
+        // 2nd access promotes to hot; with hot_capacity == 0 this used to evict the node
+        // it had just promoted.
+        assert_eq!(cache.get(&1), Some(&"one"));
+    }
+
+    // Test => put_or_modify inserts on a missing key and modifies on an existing one
+    #[test]
+    fn test_put_or_modify_inserts_then_modifies() {
+        let mut cache: Cache<&str, i32> = Cache::new(2);
+
+        cache.put_or_modify("key_a", |_| 1, |_, v| *v += 1);
+        assert_eq!(cache.peek(&"key_a"), Some(&1));
+
+        cache.put_or_modify("key_a", |_| 100, |_, v| *v += 1);
+        assert_eq!(cache.peek(&"key_a"), Some(&2));
+    }
+
+    // Test => put_or_modify on an existing key under a new_2q(0) cache must not panic
+    // (regression: touch()'s promotion used to evict the node being modified)
+    #[test]
+    fn test_put_or_modify_under_2q_zero_capacity_does_not_panic() {
+        let mut cache = Cache::new_2q(0);
+
+        cache.put(1, 1);
+        let value = cache.put_or_modify(1, |_| 99, |_, v| *v += 1);
+        assert_eq!(*value, 2);
+    }
+
+    // Test => unbounded() never evicts on put
+    #[test]
+    fn test_unbounded_never_evicts() {
+        let mut cache = Cache::unbounded();
+
+        for i in 0..1000 {
+            cache.put(i, i * 2);
+        }
+
+        assert_eq!(cache.len(), 1000);
+        assert_eq!(cache.get(&0), Some(&0));
+        assert_eq!(cache.get(&999), Some(&1998));
+    }
+
+    // Test => shrinking the capacity evicts down to the new length bound
+    #[test]
+    fn test_set_capacity_shrinks_by_len() {
+        let mut cache = Cache::new(3);
+
+        cache.put("key_a", "value_a");
+        cache.put("key_b", "value_b");
+        cache.put("key_c", "value_c");
+
+        cache.set_capacity(1);
+
+        assert_eq!(cache.len(), 1);
+        assert_eq!(cache.get(&"key_c"), Some(&"value_c"));
+        assert_eq!(cache.get(&"key_a"), None);
+        assert_eq!(cache.get(&"key_b"), None);
+    }
+
+    // Test => shrinking the capacity of a weighted cache also evicts down to the new
+    // weight bound, not just the entry count (regression: current_weight could stay
+    // above the new capacity after set_capacity on a with_weigher cache)
+    #[test]
+    fn test_set_capacity_shrinks_weighted_cache_by_weight() {
+        let mut cache = Cache::with_weigher(10, |_k: &&str, v: &String| v.len());
+
+        cache.put("key_a", "12345".to_string()); // weight 5
+        cache.put("key_b", "123".to_string()); // weight 3, total 8
+        assert_eq!(cache.weight(), 8);
+
+        cache.set_capacity(4);
+
+        assert!(cache.weight() <= 4);
+        assert_eq!(cache.get(&"key_a"), None);
+        assert_eq!(cache.get(&"key_b"), Some(&"123".to_string()));
+    }
+
+    // Test => get_mut allows in-place mutation and marks the entry as recently used
+    #[test]
+    fn test_get_mut_mutates_in_place() {
+        let mut cache = Cache::new(1);
+        cache.put("key_a", "value_a");
+
+        if let Some(value) = cache.get_mut(&"key_a") {
+            *value = "value_updated";
+        }
+
+        assert_eq!(cache.get(&"key_a"), Some(&"value_updated"));
+    }
+
+    // Test => peek reads without disturbing recency order, unlike get
+    #[test]
+    fn test_peek_does_not_affect_eviction_order() {
+        let mut cache = Cache::new(2);
+        cache.put("key_a", "value_a");
+        cache.put("key_b", "value_b");
+
+        // Peeking "key_a" must not promote it: it stays the LRU entry
+        assert_eq!(cache.peek(&"key_a"), Some(&"value_a"));
+
+        cache.put("key_c", "value_c");
+
+        assert_eq!(cache.get(&"key_a"), None);
+        assert_eq!(cache.get(&"key_b"), Some(&"value_b"));
+        assert_eq!(cache.get(&"key_c"), Some(&"value_c"));
+    }
+
+    // Test => pop_lru removes and returns the least recently used entry
+    #[test]
+    fn test_pop_lru_removes_least_recently_used() {
+        let mut cache = Cache::new(3);
+        cache.put("key_a", "value_a");
+        cache.put("key_b", "value_b");
+        cache.put("key_c", "value_c");
+
+        cache.get(&"key_a"); // key_b becomes the LRU entry
+
+        assert_eq!(cache.pop_lru(), Some(("key_b", "value_b")));
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.get(&"key_b"), None);
+
+        cache.pop_lru();
+        cache.pop_lru();
+        assert_eq!(cache.pop_lru(), None);
+    }
+
+    // Test => iter walks entries from most to least recently used, without reordering
+    #[test]
+    fn test_iter_is_recency_ordered() {
+        let mut cache = Cache::new(3);
+        cache.put("key_a", "value_a");
+        cache.put("key_b", "value_b");
+        cache.put("key_c", "value_c");
+
+        let entries: Vec<_> = cache.iter().collect();
+        assert_eq!(
+            entries,
+            vec![(&"key_c", &"value_c"), (&"key_b", &"value_b"), (&"key_a", &"value_a")]
+        );
+
+        // iter() must not have disturbed recency order
+        assert_eq!(cache.pop_lru(), Some(("key_a", "value_a")));
+    }
+
+    // Test => iter_mut allows mutating values in place, in recency order
+    #[test]
+    fn test_iter_mut_mutates_all_values() {
+        let mut cache = Cache::new(2);
+        cache.put("key_a", 1);
+        cache.put("key_b", 2);
+
+        for (_, value) in cache.iter_mut() {
+            *value *= 10;
+        }
+
+        assert_eq!(cache.get(&"key_a"), Some(&10));
+        assert_eq!(cache.get(&"key_b"), Some(&20));
+    }
+
+    // Test => keys and values each yield the recency-ordered projection of iter
+    #[test]
+    fn test_keys_and_values_match_iter() {
+        let mut cache = Cache::new(2);
+        cache.put("key_a", "value_a");
+        cache.put("key_b", "value_b");
+
+        let keys: Vec<_> = cache.keys().collect();
+        let values: Vec<_> = cache.values().collect();
+
+        assert_eq!(keys, vec![&"key_b", &"key_a"]);
+        assert_eq!(values, vec![&"value_b", &"value_a"]);
+    }
 }